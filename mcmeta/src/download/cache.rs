@@ -0,0 +1,127 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use tracing::debug;
+
+use crate::download::errors::MetadataError;
+
+/// A content-addressed store on disk, keyed by the hex-encoded sha1 of the
+/// bytes it holds. Callers that already know the expected hash of a download
+/// (from a manifest entry) can check the cache before touching the network.
+#[derive(Debug, Clone)]
+pub struct ContentCache {
+    dir: PathBuf,
+}
+
+fn is_valid_sha1(sha1: &str) -> bool {
+    sha1.len() == 40 && sha1.bytes().all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+}
+
+impl ContentCache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Joins `sha1` onto the cache directory, rejecting anything that isn't a
+    /// 40-character lowercase hex digest so a caller can never use this to
+    /// read or write outside `dir` (e.g. via `../` path traversal).
+    fn path_for(&self, sha1: &str) -> Result<PathBuf> {
+        if !is_valid_sha1(sha1) {
+            return Err(MetadataError::invalid_cache_key(sha1).into());
+        }
+        Ok(self.dir.join(sha1))
+    }
+
+    /// Returns the cached bytes for `sha1`, if present.
+    pub fn get(&self, sha1: &str) -> Result<Option<Vec<u8>>> {
+        let path = self.path_for(sha1)?;
+        if path.exists() {
+            debug!("cache hit for {sha1}");
+            Ok(Some(std::fs::read(path)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Stores `bytes` under `sha1`, creating the cache directory if needed.
+    pub fn put(&self, sha1: &str, bytes: &[u8]) -> Result<()> {
+        let path = self.path_for(sha1)?;
+        std::fs::create_dir_all(&self.dir)?;
+        std::fs::write(path, bytes)?;
+        Ok(())
+    }
+}
+
+/// Computes the hex-encoded SHA1 digest of `bytes`.
+pub fn sha1_hex(bytes: &[u8]) -> String {
+    use sha1::{Digest, Sha1};
+
+    Sha1::digest(bytes).iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// An incremental SHA1 digest for hashing a body as it streams in, instead
+/// of requiring the whole thing in memory at once.
+#[derive(Default)]
+pub struct StreamingSha1 {
+    hasher: sha1::Sha1,
+}
+
+impl StreamingSha1 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn update(&mut self, chunk: &[u8]) {
+        use sha1::Digest;
+        self.hasher.update(chunk);
+    }
+
+    pub fn finalize_hex(self) -> String {
+        use sha1::Digest;
+        self.hasher.finalize().iter().map(|b| format!("{b:02x}")).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache() -> ContentCache {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let dir = std::env::temp_dir().join(format!(
+            "mcmeta_cache_test_{}_{unique}",
+            std::process::id()
+        ));
+        ContentCache::new(dir)
+    }
+
+    #[test]
+    fn put_then_get_round_trips_bytes() {
+        let cache = temp_cache();
+        let bytes = b"hello cache";
+        let key = sha1_hex(bytes);
+
+        cache.put(&key, bytes).unwrap();
+        assert_eq!(cache.get(&key).unwrap(), Some(bytes.to_vec()));
+
+        std::fs::remove_dir_all(cache.dir).ok();
+    }
+
+    #[test]
+    fn get_misses_for_unknown_key() {
+        let cache = temp_cache();
+        let key = sha1_hex(b"never written");
+        assert_eq!(cache.get(&key).unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_non_hex_keys_as_path_traversal_guard() {
+        let cache = temp_cache();
+        assert!(cache.get("../../../../etc/cron.d/x").is_err());
+        assert!(cache.put("../../../../etc/cron.d/x", b"evil").is_err());
+        assert!(cache.get("not-forty-hex-chars").is_err());
+    }
+}
@@ -0,0 +1,23 @@
+/// A snapshot of how far an in-progress download has gotten, reported to a
+/// [`Callback`] as each chunk of the response body arrives.
+#[derive(Debug, Clone)]
+pub struct DownloadInfo {
+    pub name: String,
+    pub bytes_received: u64,
+    pub total_bytes: Option<u64>,
+}
+
+/// Implement this to drive a progress bar or emit events while a loader
+/// streams a response body in. `NoopCallback` is the default for callers
+/// that don't care about progress.
+pub trait Callback: Send + Sync {
+    fn on_progress(&self, info: &DownloadInfo);
+}
+
+/// A `Callback` that discards every update.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopCallback;
+
+impl Callback for NoopCallback {
+    fn on_progress(&self, _info: &DownloadInfo) {}
+}
@@ -0,0 +1,76 @@
+use thiserror::Error;
+
+/// Errors produced while fetching or parsing remote metadata.
+#[derive(Debug, Error)]
+pub enum MetadataError {
+    #[error("failed to parse metadata JSON: {source}\n--- body (truncated) ---\n{snippet}")]
+    Json {
+        #[source]
+        source: serde_json::Error,
+        snippet: String,
+    },
+
+    #[error("sha1 mismatch for {url}: expected {expected}, got {actual}")]
+    HashMismatch {
+        url: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("failed to parse {url} as XML: {source}")]
+    Xml {
+        url: String,
+        #[source]
+        source: roxmltree::Error,
+    },
+
+    #[error("{url} listed no versions")]
+    NoVersions { url: String },
+
+    #[error("zip archive from {url} contains no JSON entries")]
+    NoJsonEntries { url: String },
+
+    #[error("zip archive from {url} has ambiguous JSON entries, none matching the expected name: {candidates:?}")]
+    AmbiguousZipEntry { url: String, candidates: Vec<String> },
+
+    #[error("invalid sha1 cache key {key:?}: expected 40 lowercase hex characters")]
+    InvalidCacheKey { key: String },
+}
+
+impl MetadataError {
+    pub fn from_json_err(source: serde_json::Error, body: &str) -> Self {
+        let snippet: String = body.chars().take(200).collect();
+        MetadataError::Json { source, snippet }
+    }
+
+    pub fn hash_mismatch(url: impl Into<String>, expected: impl Into<String>, actual: impl Into<String>) -> Self {
+        MetadataError::HashMismatch {
+            url: url.into(),
+            expected: expected.into(),
+            actual: actual.into(),
+        }
+    }
+
+    pub fn from_xml_err(url: impl Into<String>, source: roxmltree::Error) -> Self {
+        MetadataError::Xml { url: url.into(), source }
+    }
+
+    pub fn no_versions(url: impl Into<String>) -> Self {
+        MetadataError::NoVersions { url: url.into() }
+    }
+
+    pub fn no_json_entries(url: impl Into<String>) -> Self {
+        MetadataError::NoJsonEntries { url: url.into() }
+    }
+
+    pub fn ambiguous_zip_entry(url: impl Into<String>, candidates: Vec<String>) -> Self {
+        MetadataError::AmbiguousZipEntry {
+            url: url.into(),
+            candidates,
+        }
+    }
+
+    pub fn invalid_cache_key(key: impl Into<String>) -> Self {
+        MetadataError::InvalidCacheKey { key: key.into() }
+    }
+}
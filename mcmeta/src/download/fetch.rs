@@ -0,0 +1,62 @@
+use futures_util::StreamExt;
+use tracing::debug;
+
+use anyhow::Result;
+
+use crate::download::cache::{sha1_hex, ContentCache};
+use crate::download::callback::{Callback, DownloadInfo};
+use crate::download::errors::MetadataError;
+use crate::download::retry::{retry_download, RetryPolicy};
+
+/// Fetches `url`, returning the cached bytes instead if `expected_sha1` is
+/// already present in `cache`. Retries the whole request-plus-body-read
+/// under `retry_policy`; when the bytes are fetched over the network and
+/// `expected_sha1` is given, the computed hash is checked before the result
+/// is handed back and written into the cache. Shared by every
+/// `MetadataSource` adapter so none of them drift in robustness (caching,
+/// retries, or progress reporting) from the others.
+pub async fn fetch_verified(
+    client: &reqwest::Client,
+    url: &str,
+    expected_sha1: Option<&str>,
+    cache: &ContentCache,
+    retry_policy: &RetryPolicy,
+    callback: &dyn Callback,
+) -> Result<Vec<u8>> {
+    if let Some(expected) = expected_sha1 {
+        if let Some(cached) = cache.get(expected)? {
+            debug!("using cached copy of {:#?}", url);
+            return Ok(cached);
+        }
+    }
+
+    let bytes = retry_download(retry_policy, || async {
+        let response = client.get(url).send().await?.error_for_status()?;
+        let total_bytes = response.content_length();
+        let mut stream = response.bytes_stream();
+        let mut bytes = Vec::new();
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            bytes.extend_from_slice(&chunk);
+            callback.on_progress(&DownloadInfo {
+                name: url.to_string(),
+                bytes_received: bytes.len() as u64,
+                total_bytes,
+            });
+        }
+
+        Ok(bytes)
+    })
+    .await?;
+
+    if let Some(expected) = expected_sha1 {
+        let actual = sha1_hex(&bytes);
+        if actual != expected {
+            return Err(MetadataError::hash_mismatch(url, expected, actual).into());
+        }
+        cache.put(expected, &bytes)?;
+    }
+
+    Ok(bytes)
+}
@@ -0,0 +1,8 @@
+pub mod cache;
+pub mod callback;
+pub mod errors;
+pub mod fetch;
+pub mod mojang;
+pub mod retry;
+pub mod source;
+pub mod sources;
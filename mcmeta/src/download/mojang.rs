@@ -1,21 +1,62 @@
 use libmcmeta::models::mojang::{MinecraftVersion, MojangVersionManifest};
 use serde::Deserialize;
 use serde_valid::Validate;
-use tempdir::TempDir;
 use tracing::debug;
 
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 
+use crate::download::cache::ContentCache;
+use crate::download::callback::{Callback, NoopCallback};
 use crate::download::errors::MetadataError;
+use crate::download::fetch::fetch_verified;
+use crate::download::retry::RetryPolicy;
 
 fn default_download_url() -> String {
     "https://piston-meta.mojang.com/mc/game/version_manifest_v2.json".to_string()
 }
 
+fn default_cache_dir() -> String {
+    ".cache/mcmeta/mojang".to_string()
+}
+
+fn default_max_retries() -> u32 {
+    2
+}
+
+fn default_retry_base_ms() -> u64 {
+    250
+}
+
+fn default_retry_multiplier() -> f64 {
+    RetryPolicy::default().multiplier
+}
+
+fn default_retry_jitter_min() -> f64 {
+    RetryPolicy::default().jitter_min
+}
+
+fn default_retry_jitter_max() -> f64 {
+    RetryPolicy::default().jitter_max
+}
+
 #[derive(Deserialize, Debug)]
 struct DownloadConfig {
     #[serde(default = "default_download_url")]
     pub manifest_url: String,
+    #[serde(default = "default_cache_dir")]
+    pub cache_dir: String,
+    /// Number of retries after an initial failed attempt, i.e. the upstream
+    /// is called up to `max_retries + 1` times in total.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    #[serde(default = "default_retry_base_ms")]
+    pub retry_base_ms: u64,
+    #[serde(default = "default_retry_multiplier")]
+    pub retry_multiplier: f64,
+    #[serde(default = "default_retry_jitter_min")]
+    pub retry_jitter_min: f64,
+    #[serde(default = "default_retry_jitter_max")]
+    pub retry_jitter_max: f64,
 }
 
 impl DownloadConfig {
@@ -26,19 +67,36 @@ impl DownloadConfig {
 
         config.try_deserialize::<'_, Self>().map_err(Into::into)
     }
+
+    fn cache(&self) -> ContentCache {
+        ContentCache::new(&self.cache_dir)
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: self.max_retries + 1,
+            base_delay_ms: self.retry_base_ms,
+            multiplier: self.retry_multiplier,
+            jitter_min: self.retry_jitter_min,
+            jitter_max: self.retry_jitter_max,
+        }
+    }
 }
 
 pub async fn load_manifest() -> Result<MojangVersionManifest> {
     let client = reqwest::Client::new();
     let config = DownloadConfig::from_config()?;
 
-    let body = client
-        .get(&config.manifest_url)
-        .send()
-        .await?
-        .error_for_status()?
-        .text()
-        .await?;
+    let bytes = fetch_verified(
+        &client,
+        &config.manifest_url,
+        None,
+        &config.cache(),
+        &config.retry_policy(),
+        &NoopCallback,
+    )
+    .await?;
+    let body = String::from_utf8(bytes)?;
 
     let manifest: MojangVersionManifest =
         serde_json::from_str(&body).map_err(|err| MetadataError::from_json_err(err, &body))?;
@@ -46,70 +104,157 @@ pub async fn load_manifest() -> Result<MojangVersionManifest> {
     Ok(manifest)
 }
 
-pub async fn load_version_manifest(version_url: &str) -> Result<MinecraftVersion> {
+/// Fetches and sha1-verifies the raw bytes of a version manifest without
+/// parsing them. Callers that need to hand back the exact upstream document
+/// (e.g. `MetadataSource` adapters) should use this instead of
+/// `load_version_manifest`, which returns only the parsed model; serializing
+/// that model again would silently drop any field it doesn't capture.
+pub async fn fetch_version_bytes(
+    version_url: &str,
+    expected_sha1: Option<&str>,
+    callback: Option<&dyn Callback>,
+) -> Result<Vec<u8>> {
     let client = reqwest::Client::new();
+    let config = DownloadConfig::from_config()?;
 
     debug!("Fetching version manifest from {:#?}", version_url);
 
-    let body = client
-        .get(version_url)
-        .send()
-        .await?
-        .error_for_status()?
-        .text()
-        .await?;
+    fetch_verified(
+        &client,
+        version_url,
+        expected_sha1,
+        &config.cache(),
+        &config.retry_policy(),
+        callback.unwrap_or(&NoopCallback),
+    )
+    .await
+}
+
+pub async fn load_version_manifest(
+    version_url: &str,
+    expected_sha1: Option<&str>,
+    callback: Option<&dyn Callback>,
+) -> Result<MinecraftVersion> {
+    let bytes = fetch_version_bytes(version_url, expected_sha1, callback).await?;
+    let body = String::from_utf8(bytes)?;
     let manifest: MinecraftVersion =
         serde_json::from_str(&body).map_err(|err| MetadataError::from_json_err(err, &body))?;
     manifest.validate()?;
     Ok(manifest)
 }
 
-pub async fn load_zipped_version(version_url: &str) -> Result<MinecraftVersion> {
+/// Picks which `.json` entry in a version zip holds the manifest, when more
+/// than one is present. Prefers an entry whose name contains `version_id`,
+/// then falls back to a single unambiguous entry, and otherwise reports every
+/// candidate so the caller can see why the pick was ambiguous.
+fn select_version_entry<'a>(
+    version_url: &str,
+    version_id: Option<&str>,
+    candidates: &'a [String],
+) -> Result<&'a str> {
+    if let Some(id) = version_id {
+        let matches: Vec<&String> = candidates.iter().filter(|name| name.contains(id)).collect();
+        match matches.as_slice() {
+            [] => {}
+            [single] => return Ok(single),
+            multiple => {
+                let names: Vec<String> = multiple.iter().map(|name| name.to_string()).collect();
+                return Err(MetadataError::ambiguous_zip_entry(version_url, names).into());
+            }
+        }
+    }
+
+    match candidates {
+        [] => Err(MetadataError::no_json_entries(version_url).into()),
+        [single] => Ok(single),
+        multiple => Err(MetadataError::ambiguous_zip_entry(version_url, multiple.to_vec()).into()),
+    }
+}
+
+pub async fn load_zipped_version(
+    version_url: &str,
+    expected_sha1: Option<&str>,
+    version_id: Option<&str>,
+    callback: Option<&dyn Callback>,
+) -> Result<MinecraftVersion> {
     use std::io::prelude::*;
 
     let client = reqwest::Client::new();
+    let config = DownloadConfig::from_config()?;
 
     debug!("Fetching zipped version from {:#?}", version_url);
 
-    let file_response = client.get(version_url).send().await?.error_for_status()?;
-
-    let tmp_dir = TempDir::new("mcmeta_mojang_zip")?;
-    let dest_path = {
-        let fname = file_response
-            .url()
-            .path_segments()
-            .and_then(|segments| segments.last())
-            .and_then(|name| if name.is_empty() { None } else { Some(name) })
-            .unwrap_or("tmp.zip");
-
-        tmp_dir.path().join(fname)
-    };
-
-    {
-        // write to file, context drop to flush and close
-        let mut file = std::fs::File::create(&dest_path)?;
-        let mut content = std::io::Cursor::new(file_response.bytes().await?);
-        std::io::copy(&mut content, &mut file)?;
+    let bytes = fetch_verified(
+        &client,
+        version_url,
+        expected_sha1,
+        &config.cache(),
+        &config.retry_policy(),
+        callback.unwrap_or(&NoopCallback),
+    )
+    .await?;
+
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes))?;
+
+    let candidates: Vec<String> = (0..archive.len())
+        .map(|i| Ok(archive.by_index(i)?.name().to_string()))
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .filter(|name| name.ends_with(".json"))
+        .collect();
+
+    let chosen = select_version_entry(version_url, version_id, &candidates)?.to_string();
+    debug!("Using {} as version json", chosen);
+
+    let mut contents = String::new();
+    archive.by_name(&chosen)?.read_to_string(&mut contents)?;
+
+    let manifest: MinecraftVersion =
+        serde_json::from_str(&contents).map_err(|err| MetadataError::from_json_err(err, &contents))?;
+    manifest.validate()?;
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
     }
 
-    let file = std::fs::File::open(&dest_path)?;
+    #[test]
+    fn select_version_entry_prefers_id_match() {
+        let entries = candidates(&["install_profile.json", "1.20.1.json", "another.json"]);
+        let chosen = select_version_entry("http://example.invalid", Some("1.20.1"), &entries).unwrap();
+        assert_eq!(chosen, "1.20.1.json");
+    }
 
-    let mut archive = zip::ZipArchive::new(file)?;
+    #[test]
+    fn select_version_entry_falls_back_to_single_candidate() {
+        let entries = candidates(&["version.json"]);
+        let chosen = select_version_entry("http://example.invalid", None, &entries).unwrap();
+        assert_eq!(chosen, "version.json");
+    }
 
-    let mut manifest: Option<MinecraftVersion> = None;
-    for i in 0..archive.len() {
-        let mut zfile = archive.by_index(i)?;
-        if zfile.name().ends_with(".json") {
-            debug!("Found {} as version json", zfile.name());
-            let mut contents = String::new();
-            zfile.read_to_string(&mut contents).unwrap();
+    #[test]
+    fn select_version_entry_errors_when_ambiguous() {
+        let entries = candidates(&["a.json", "b.json"]);
+        let err = select_version_entry("http://example.invalid", None, &entries).unwrap_err();
+        assert!(err.to_string().contains("ambiguous"));
+    }
 
-            manifest = Some(
-                serde_json::from_str(&contents)
-                    .map_err(|err| MetadataError::from_json_err(err, &contents))?,
-            );
-        }
+    #[test]
+    fn select_version_entry_errors_when_empty() {
+        let entries: Vec<String> = Vec::new();
+        let err = select_version_entry("http://example.invalid", None, &entries).unwrap_err();
+        assert!(err.to_string().contains("no JSON entries"));
     }
 
-    Ok(manifest.ok_or(anyhow!("Unable to find version manifest"))?)
+    #[test]
+    fn select_version_entry_errors_when_id_match_is_ambiguous() {
+        let entries = candidates(&["1.20.json", "1.20.1.json"]);
+        let err = select_version_entry("http://example.invalid", Some("1.20"), &entries).unwrap_err();
+        assert!(err.to_string().contains("ambiguous"));
+    }
 }
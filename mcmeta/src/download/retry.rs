@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use rand::Rng;
+use tracing::warn;
+
+/// Exponential backoff with jitter for retrying flaky upstream requests.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub base_delay_ms: u64,
+    pub multiplier: f64,
+    pub jitter_min: f64,
+    pub jitter_max: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay_ms: 250,
+            multiplier: 2.0,
+            jitter_min: 0.5,
+            jitter_max: 1.5,
+        }
+    }
+}
+
+impl RetryPolicy {
+    fn delay_for(&self, attempt: u32) -> Duration {
+        let exp = self.base_delay_ms as f64 * self.multiplier.powi(attempt as i32);
+        let jitter = rand::thread_rng().gen_range(self.jitter_min..self.jitter_max);
+        Duration::from_millis((exp * jitter) as u64)
+    }
+}
+
+fn is_retryable_transport_err(err: &reqwest::Error) -> bool {
+    err.is_connect() || err.is_timeout() || err.is_body() || err.status().is_some_and(|s| s.is_server_error())
+}
+
+/// Runs `op` up to `policy.max_attempts` times, retrying on connection
+/// errors, timeouts, and 5xx responses; 4xx responses fail immediately since
+/// retrying them can't help. Returns the first successful response, or the
+/// last error once attempts are exhausted.
+pub async fn retry_request<F, Fut>(policy: &RetryPolicy, mut op: F) -> Result<reqwest::Response>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = reqwest::Result<reqwest::Response>>,
+{
+    let mut attempt = 0;
+    loop {
+        match op().await {
+            Ok(response) => {
+                let status = response.status();
+                if status.is_server_error() && attempt + 1 < policy.max_attempts {
+                    warn!("{status} from upstream, retrying (attempt {attempt})");
+                    attempt += 1;
+                    tokio::time::sleep(policy.delay_for(attempt)).await;
+                    continue;
+                }
+                return Ok(response.error_for_status()?);
+            }
+            Err(err) if is_retryable_transport_err(&err) && attempt + 1 < policy.max_attempts => {
+                warn!("transport error, retrying (attempt {attempt}): {err}");
+                attempt += 1;
+                tokio::time::sleep(policy.delay_for(attempt)).await;
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
+/// Runs `op` (a full request followed by reading its entire response body)
+/// up to `policy.max_attempts` times, retrying on connection errors,
+/// timeouts, 5xx responses, and body-read errors that surface mid-stream —
+/// e.g. a connection dropped partway through a large artifact download.
+/// Unlike `retry_request`, this covers the whole attempt, not just
+/// establishing the connection and reading the status line.
+pub async fn retry_download<F, Fut>(policy: &RetryPolicy, mut attempt_fn: F) -> Result<Vec<u8>>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = reqwest::Result<Vec<u8>>>,
+{
+    let mut attempt = 0;
+    loop {
+        match attempt_fn().await {
+            Ok(bytes) => return Ok(bytes),
+            Err(err) if is_retryable_transport_err(&err) && attempt + 1 < policy.max_attempts => {
+                warn!("download failed, retrying (attempt {attempt}): {err}");
+                attempt += 1;
+                tokio::time::sleep(policy.delay_for(attempt)).await;
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use crate::download::mojang;
+use crate::download::sources::modrinth::ModrinthSource;
+
+/// A dependency declared by a version, as reported by sources that track
+/// them (e.g. Modrinth). Sources with no such concept (e.g. the vanilla
+/// Mojang manifest) always report an empty list.
+#[derive(Debug, Clone)]
+pub struct Dependency {
+    pub project_id: Option<String>,
+    pub version_id: Option<String>,
+    pub dependency_type: String,
+}
+
+/// Source-agnostic information about a resolvable version: enough to fetch
+/// and verify it, regardless of which upstream produced it.
+#[derive(Debug, Clone)]
+pub struct VersionInfo {
+    pub id: String,
+    pub url: String,
+    pub sha1: Option<String>,
+    pub dependencies: Vec<Dependency>,
+}
+
+/// Abstracts "list versions" and "fetch a version" over whichever upstream a
+/// project's metadata actually lives behind. The vanilla Mojang manifest
+/// (`MojangSource`) is one implementation among several.
+#[async_trait]
+pub trait MetadataSource: Send + Sync {
+    async fn list_versions(&self) -> Result<Vec<VersionInfo>>;
+    async fn fetch_version(&self, version: &VersionInfo) -> Result<Vec<u8>>;
+}
+
+/// The vanilla Mojang version manifest, adapted to [`MetadataSource`].
+pub struct MojangSource;
+
+#[async_trait]
+impl MetadataSource for MojangSource {
+    async fn list_versions(&self) -> Result<Vec<VersionInfo>> {
+        let manifest = mojang::load_manifest().await?;
+        Ok(manifest
+            .versions
+            .into_iter()
+            .map(|version| VersionInfo {
+                id: version.id,
+                url: version.url,
+                sha1: version.sha1,
+                dependencies: Vec::new(),
+            })
+            .collect())
+    }
+
+    async fn fetch_version(&self, version: &VersionInfo) -> Result<Vec<u8>> {
+        mojang::fetch_version_bytes(&version.url, version.sha1.as_deref(), None).await
+    }
+}
+
+fn default_source_kind() -> String {
+    "mojang".to_string()
+}
+
+#[derive(Deserialize, Debug)]
+struct SourceSelectConfig {
+    #[serde(default = "default_source_kind")]
+    pub kind: String,
+}
+
+impl SourceSelectConfig {
+    fn from_config() -> Result<Self> {
+        let config = config::Config::builder()
+            .add_source(config::Environment::with_prefix("MCMETA_SOURCE"))
+            .build()?;
+
+        config.try_deserialize::<'_, Self>().map_err(Into::into)
+    }
+}
+
+/// Picks the configured [`MetadataSource`] via `MCMETA_SOURCE_KIND`
+/// (`mojang` by default, or `modrinth`).
+pub fn active_source() -> Result<Box<dyn MetadataSource>> {
+    let config = SourceSelectConfig::from_config()?;
+    match config.kind.as_str() {
+        "mojang" => Ok(Box::new(MojangSource)),
+        "modrinth" => Ok(Box::new(ModrinthSource)),
+        other => Err(anyhow!("unknown metadata source {other:?}")),
+    }
+}
@@ -0,0 +1,108 @@
+use serde::Deserialize;
+use serde_valid::Validate;
+
+use anyhow::Result;
+
+use crate::download::sources::maven::{load_maven_versions, Coordinates, Repository, RepositoryKind};
+
+fn default_repository_url() -> String {
+    "https://maven.minecraftforge.net".to_string()
+}
+
+fn default_group() -> String {
+    "net.minecraftforge".to_string()
+}
+
+fn default_artifact() -> String {
+    "forge".to_string()
+}
+
+/// Configures which Maven repository and coordinates to resolve versions
+/// from. Defaults to Forge's own Maven; point `MCMETA_FORGE_GROUP` /
+/// `MCMETA_FORGE_ARTIFACT` / `MCMETA_FORGE_REPOSITORY_URL` at NeoForge's
+/// instead to resolve that loader's versions through the same code path.
+#[derive(Deserialize, Debug)]
+struct ForgeConfig {
+    #[serde(default = "default_repository_url")]
+    pub repository_url: String,
+    #[serde(default = "default_group")]
+    pub group: String,
+    #[serde(default = "default_artifact")]
+    pub artifact: String,
+}
+
+impl ForgeConfig {
+    fn from_config() -> Result<Self> {
+        let config = config::Config::builder()
+            .add_source(config::Environment::with_prefix("MCMETA_FORGE"))
+            .build()?;
+
+        config.try_deserialize::<'_, Self>().map_err(Into::into)
+    }
+
+    fn repository(&self) -> Repository {
+        Repository {
+            url: self.repository_url.clone(),
+            kind: RepositoryKind::Maven,
+        }
+    }
+}
+
+/// Lists the versions published to the configured Forge/NeoForge Maven repository.
+pub async fn load_forge_versions() -> Result<Vec<String>> {
+    let config = ForgeConfig::from_config()?;
+    load_maven_versions(&config.repository(), &config.group, &config.artifact).await
+}
+
+/// Builds the download URL for a specific Forge/NeoForge version's jar.
+pub fn forge_artifact_url(version: &str, classifier: Option<&str>) -> Result<String> {
+    let config = ForgeConfig::from_config()?;
+    artifact_url(&config, version, classifier)
+}
+
+fn artifact_url(config: &ForgeConfig, version: &str, classifier: Option<&str>) -> Result<String> {
+    let coords = Coordinates {
+        group: config.group.clone(),
+        artifact: config.artifact.clone(),
+        version: version.to_string(),
+        classifier: classifier.map(str::to_string),
+    };
+    coords.validate()?;
+    Ok(config.repository().artifact_url(&coords))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> ForgeConfig {
+        ForgeConfig {
+            repository_url: default_repository_url(),
+            group: default_group(),
+            artifact: default_artifact(),
+        }
+    }
+
+    #[test]
+    fn artifact_url_without_classifier() {
+        let url = artifact_url(&config(), "1.20.1-47.2.0", None).unwrap();
+        assert_eq!(
+            url,
+            "https://maven.minecraftforge.net/net/minecraftforge/forge/1.20.1-47.2.0/forge-1.20.1-47.2.0.jar"
+        );
+    }
+
+    #[test]
+    fn artifact_url_with_classifier() {
+        let url = artifact_url(&config(), "1.20.1-47.2.0", Some("installer")).unwrap();
+        assert_eq!(
+            url,
+            "https://maven.minecraftforge.net/net/minecraftforge/forge/1.20.1-47.2.0/forge-1.20.1-47.2.0-installer.jar"
+        );
+    }
+
+    #[test]
+    fn artifact_url_rejects_empty_version() {
+        assert!(artifact_url(&config(), "", None).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use serde_valid::Validate;
+use tracing::debug;
+
+use anyhow::Result;
+
+use crate::download::cache::ContentCache;
+use crate::download::callback::NoopCallback;
+use crate::download::errors::MetadataError;
+use crate::download::fetch::fetch_verified;
+use crate::download::retry::RetryPolicy;
+
+/// How a [`Repository`] serves artifacts: a Maven layout with
+/// `maven-metadata.xml` per artifact, or a repository that only exposes
+/// direct download URLs with no metadata index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RepositoryKind {
+    Maven,
+    Direct,
+}
+
+/// A repository hosting modloader artifacts, such as Forge's or NeoForge's
+/// Maven.
+#[derive(Debug, Clone)]
+pub struct Repository {
+    pub url: String,
+    pub kind: RepositoryKind,
+}
+
+/// A fully-qualified Maven artifact coordinate.
+#[derive(Debug, Clone, Validate)]
+pub struct Coordinates {
+    #[validate(min_length = 1)]
+    pub group: String,
+    #[validate(min_length = 1)]
+    pub artifact: String,
+    #[validate(min_length = 1)]
+    pub version: String,
+    pub classifier: Option<String>,
+}
+
+impl Coordinates {
+    fn group_path(&self) -> String {
+        self.group.replace('.', "/")
+    }
+
+    /// The `group/artifact/version` directory this coordinate lives under.
+    pub fn artifact_dir(&self) -> String {
+        format!("{}/{}/{}", self.group_path(), self.artifact, self.version)
+    }
+
+    /// The jar filename for this coordinate, e.g. `artifact-version[-classifier].jar`.
+    pub fn jar_name(&self) -> String {
+        match &self.classifier {
+            Some(classifier) => format!("{}-{}-{}.jar", self.artifact, self.version, classifier),
+            None => format!("{}-{}.jar", self.artifact, self.version),
+        }
+    }
+}
+
+impl Repository {
+    /// Builds the full download URL for `coords` in this repository.
+    pub fn artifact_url(&self, coords: &Coordinates) -> String {
+        format!(
+            "{}/{}/{}",
+            self.url.trim_end_matches('/'),
+            coords.artifact_dir(),
+            coords.jar_name()
+        )
+    }
+
+    fn metadata_url(&self, group: &str, artifact: &str) -> String {
+        format!(
+            "{}/{}/{}/maven-metadata.xml",
+            self.url.trim_end_matches('/'),
+            group.replace('.', "/"),
+            artifact
+        )
+    }
+}
+
+/// Fetches and parses `maven-metadata.xml` for `group:artifact` in
+/// `repository`, returning the published `<version>` entries in document
+/// order (oldest first, matching Maven's own convention).
+pub async fn load_maven_versions(
+    repository: &Repository,
+    group: &str,
+    artifact: &str,
+) -> Result<Vec<String>> {
+    if repository.kind != RepositoryKind::Maven {
+        return Err(anyhow::anyhow!("{} is not a Maven repository", repository.url));
+    }
+
+    let client = reqwest::Client::new();
+    let url = repository.metadata_url(group, artifact);
+
+    debug!("Fetching Maven metadata from {:#?}", url);
+
+    let bytes = fetch_verified(
+        &client,
+        &url,
+        None,
+        &ContentCache::new(".cache/mcmeta/maven"),
+        &RetryPolicy::default(),
+        &NoopCallback,
+    )
+    .await?;
+    let body = String::from_utf8(bytes)?;
+
+    parse_maven_versions(&body, &url)
+}
+
+/// Extracts the `<version>` entries from a `maven-metadata.xml` document,
+/// in document order. Split out from [`load_maven_versions`] so the parsing
+/// logic can be exercised without a network round-trip.
+fn parse_maven_versions(body: &str, url: &str) -> Result<Vec<String>> {
+    let doc = roxmltree::Document::parse(body).map_err(|err| MetadataError::from_xml_err(url, err))?;
+
+    let versions: Vec<String> = doc
+        .descendants()
+        .filter(|node| node.has_tag_name("version"))
+        .filter_map(|node| node.text().map(str::to_string))
+        .collect();
+
+    if versions.is_empty() {
+        return Err(MetadataError::no_versions(url).into());
+    }
+
+    Ok(versions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coords(version: &str, classifier: Option<&str>) -> Coordinates {
+        Coordinates {
+            group: "net.minecraftforge".to_string(),
+            artifact: "forge".to_string(),
+            version: version.to_string(),
+            classifier: classifier.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn artifact_dir_replaces_dots_in_group_with_slashes() {
+        assert_eq!(coords("1.20.1-47.2.0", None).artifact_dir(), "net/minecraftforge/forge/1.20.1-47.2.0");
+    }
+
+    #[test]
+    fn jar_name_without_classifier() {
+        assert_eq!(coords("1.20.1-47.2.0", None).jar_name(), "forge-1.20.1-47.2.0.jar");
+    }
+
+    #[test]
+    fn jar_name_with_classifier() {
+        assert_eq!(coords("1.20.1-47.2.0", Some("installer")).jar_name(), "forge-1.20.1-47.2.0-installer.jar");
+    }
+
+    #[test]
+    fn artifact_url_joins_repository_and_coordinates() {
+        let repository = Repository {
+            url: "https://maven.minecraftforge.net/".to_string(),
+            kind: RepositoryKind::Maven,
+        };
+        assert_eq!(
+            repository.artifact_url(&coords("1.20.1-47.2.0", None)),
+            "https://maven.minecraftforge.net/net/minecraftforge/forge/1.20.1-47.2.0/forge-1.20.1-47.2.0.jar"
+        );
+    }
+
+    #[test]
+    fn parse_maven_versions_returns_entries_in_document_order() {
+        let xml = r#"<metadata>
+            <groupId>net.minecraftforge</groupId>
+            <artifactId>forge</artifactId>
+            <versioning>
+                <versions>
+                    <version>1.20.1-47.1.0</version>
+                    <version>1.20.1-47.2.0</version>
+                </versions>
+            </versioning>
+        </metadata>"#;
+
+        let versions = parse_maven_versions(xml, "http://example.invalid/maven-metadata.xml").unwrap();
+        assert_eq!(versions, vec!["1.20.1-47.1.0", "1.20.1-47.2.0"]);
+    }
+
+    #[test]
+    fn parse_maven_versions_errors_when_none_present() {
+        let xml = r#"<metadata><versioning><versions></versions></versioning></metadata>"#;
+        let err = parse_maven_versions(xml, "http://example.invalid/maven-metadata.xml").unwrap_err();
+        assert!(err.to_string().contains("no versions"));
+    }
+
+    #[test]
+    fn parse_maven_versions_errors_on_invalid_xml() {
+        let err = parse_maven_versions("not xml", "http://example.invalid/maven-metadata.xml").unwrap_err();
+        assert!(err.to_string().contains("as XML"));
+    }
+}
@@ -0,0 +1,3 @@
+pub mod forge;
+pub mod maven;
+pub mod modrinth;
@@ -0,0 +1,172 @@
+use serde::Deserialize;
+
+use anyhow::{anyhow, Result};
+
+use crate::download::callback::NoopCallback;
+use crate::download::cache::ContentCache;
+use crate::download::errors::MetadataError;
+use crate::download::fetch::fetch_verified;
+use crate::download::retry::RetryPolicy;
+use crate::download::source::{Dependency, MetadataSource, VersionInfo};
+
+fn default_api_url() -> String {
+    "https://api.modrinth.com/v2".to_string()
+}
+
+fn default_cache_dir() -> String {
+    ".cache/mcmeta/modrinth".to_string()
+}
+
+fn default_max_retries() -> u32 {
+    2
+}
+
+fn default_retry_base_ms() -> u64 {
+    250
+}
+
+fn default_retry_multiplier() -> f64 {
+    RetryPolicy::default().multiplier
+}
+
+fn default_retry_jitter_min() -> f64 {
+    RetryPolicy::default().jitter_min
+}
+
+fn default_retry_jitter_max() -> f64 {
+    RetryPolicy::default().jitter_max
+}
+
+#[derive(Deserialize, Debug)]
+struct ModrinthConfig {
+    pub project_id: String,
+    #[serde(default = "default_api_url")]
+    pub api_url: String,
+    #[serde(default = "default_cache_dir")]
+    pub cache_dir: String,
+    /// Number of retries after an initial failed attempt, i.e. the upstream
+    /// is called up to `max_retries + 1` times in total.
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    #[serde(default = "default_retry_base_ms")]
+    pub retry_base_ms: u64,
+    #[serde(default = "default_retry_multiplier")]
+    pub retry_multiplier: f64,
+    #[serde(default = "default_retry_jitter_min")]
+    pub retry_jitter_min: f64,
+    #[serde(default = "default_retry_jitter_max")]
+    pub retry_jitter_max: f64,
+}
+
+impl ModrinthConfig {
+    fn from_config() -> Result<Self> {
+        let config = config::Config::builder()
+            .add_source(config::Environment::with_prefix("MCMETA_MODRINTH"))
+            .build()?;
+
+        config.try_deserialize::<'_, Self>().map_err(Into::into)
+    }
+
+    fn cache(&self) -> ContentCache {
+        ContentCache::new(&self.cache_dir)
+    }
+
+    fn retry_policy(&self) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: self.max_retries + 1,
+            base_delay_ms: self.retry_base_ms,
+            multiplier: self.retry_multiplier,
+            jitter_min: self.retry_jitter_min,
+            jitter_max: self.retry_jitter_max,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ModrinthHashes {
+    sha1: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct ModrinthFile {
+    url: String,
+    primary: bool,
+    hashes: ModrinthHashes,
+}
+
+#[derive(Deserialize, Debug)]
+struct ModrinthDependency {
+    project_id: Option<String>,
+    version_id: Option<String>,
+    dependency_type: String,
+}
+
+#[derive(Deserialize, Debug)]
+struct ModrinthVersion {
+    id: String,
+    files: Vec<ModrinthFile>,
+    #[serde(default)]
+    dependencies: Vec<ModrinthDependency>,
+}
+
+/// A [`MetadataSource`] backed by Modrinth's `project/{id}/version` endpoint.
+pub struct ModrinthSource;
+
+impl ModrinthSource {
+    fn primary_file(version: &ModrinthVersion) -> Option<&ModrinthFile> {
+        version.files.iter().find(|file| file.primary).or_else(|| version.files.first())
+    }
+}
+
+#[async_trait::async_trait]
+impl MetadataSource for ModrinthSource {
+    async fn list_versions(&self) -> Result<Vec<VersionInfo>> {
+        let config = ModrinthConfig::from_config()?;
+        let client = reqwest::Client::new();
+        let url = format!("{}/project/{}/version", config.api_url, config.project_id);
+
+        let bytes = fetch_verified(&client, &url, None, &config.cache(), &config.retry_policy(), &NoopCallback)
+            .await?;
+        let body = String::from_utf8(bytes)?;
+
+        let versions: Vec<ModrinthVersion> =
+            serde_json::from_str(&body).map_err(|err| MetadataError::from_json_err(err, &body))?;
+
+        versions
+            .iter()
+            .map(|version| {
+                let file = Self::primary_file(version)
+                    .ok_or_else(|| anyhow!("version {} has no files", version.id))?;
+                Ok(VersionInfo {
+                    id: version.id.clone(),
+                    url: file.url.clone(),
+                    sha1: Some(file.hashes.sha1.clone()),
+                    dependencies: version
+                        .dependencies
+                        .iter()
+                        .map(|dep| Dependency {
+                            project_id: dep.project_id.clone(),
+                            version_id: dep.version_id.clone(),
+                            dependency_type: dep.dependency_type.clone(),
+                        })
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+
+    async fn fetch_version(&self, version: &VersionInfo) -> Result<Vec<u8>> {
+        let config = ModrinthConfig::from_config()?;
+        let client = reqwest::Client::new();
+
+        fetch_verified(
+            &client,
+            &version.url,
+            version.sha1.as_deref(),
+            &config.cache(),
+            &config.retry_policy(),
+            &NoopCallback,
+        )
+        .await
+    }
+}